@@ -11,6 +11,18 @@ const HEADER_NAME: &str = "FastNoise_C.h";
 const WASM_PREBUILT_REPO: &str = "api-haus/fastnoise2-rs";
 const WASM_PREBUILT_TAG: &str = "wasm-prebuilt-v1";
 
+// `wasm-opt` feature knobs
+const WASM_OPT_PATH_KEY: &str = "WASM_OPT_PATH";
+const WASM_OPT_LEVEL_KEY: &str = "WASM_OPT_LEVEL";
+const WASM_MAX_MEMORY_PAGES_KEY: &str = "WASM_MAX_MEMORY_PAGES";
+const WASM_PAGE_SIZE_BYTES: u64 = 64 * 1024;
+const DEFAULT_WASM_MAX_MEMORY_PAGES: u64 = 1024; // 64 MiB
+
+// `system` feature knobs
+const SYSTEM_LIBRARY_PATH_KEY: &str = "FASTNOISE2_LIBRARY_PATH";
+const PKG_CONFIG_NAME: &str = "fastnoise2";
+const STANDARD_SYSTEM_PREFIXES: [&str; 2] = ["/usr/local", "/usr"];
+
 fn main() {
   if env::var("DOCS_RS").is_ok() {
     println!("cargo:warning=docs.rs compilation detected, only bindings will be generated");
@@ -22,23 +34,41 @@ fn main() {
   println!("cargo:rerun-if-env-changed={LIB_DIR_KEY}");
   println!("cargo:rerun-if-env-changed={BINDINGS_CACHE_KEY}");
   println!("cargo:rerun-if-env-changed=EMSDK");
+  println!("cargo:rerun-if-env-changed=WASI_SDK_PATH");
+  println!("cargo:rerun-if-env-changed={WASM_OPT_PATH_KEY}");
+  println!("cargo:rerun-if-env-changed={WASM_OPT_LEVEL_KEY}");
+  println!("cargo:rerun-if-env-changed={WASM_MAX_MEMORY_PAGES_KEY}");
+  println!("cargo:rerun-if-env-changed={SYSTEM_LIBRARY_PATH_KEY}");
 
   let target_arch = env::var("CARGO_CFG_TARGET_ARCH").unwrap();
 
   // WASM builds use pure WASM with SIMD128
   if target_arch == "wasm32" {
-    build_wasm();
+    let target_os = env::var("CARGO_CFG_TARGET_OS").unwrap_or_default();
+    match target_os.as_str() {
+      // wasm32-unknown-unknown: no host ABI at all, so wasm-bindgen can link
+      // against the static lib directly without Emscripten's JS glue.
+      "unknown" => build_wasm_unknown_unknown(),
+      // wasm32-wasip1 (aka wasm32-wasi): standalone reactor module for
+      // server-side runtimes such as wasmtime/wasmer.
+      "wasi" => build_wasm_wasi(),
+      _ => build_wasm(),
+    }
     return; // WASM doesn't need C++ stdlib linking
   }
 
   // Native builds follow existing logic
   let feature_build_from_source = env::var("CARGO_FEATURE_BUILD_FROM_SOURCE").is_ok();
+  let feature_system = env::var("CARGO_FEATURE_SYSTEM").is_ok();
 
   if feature_build_from_source {
     println!(
       "cargo:warning=feature 'build-from-source' is enabled; building FastNoise2 from source"
     );
     build_from_source();
+  } else if feature_system {
+    println!("cargo:warning=feature 'system' is enabled; linking against an installed FastNoise2");
+    link_system_fastnoise2();
   } else if let Ok(lib_dir) = env::var(LIB_DIR_KEY) {
     println!("cargo:warning=using precompiled library located in '{lib_dir}'");
     println!("cargo:rustc-link-search=native={lib_dir}");
@@ -150,20 +180,10 @@ fn try_download_wasm_prebuilt() -> Option<PathBuf> {
 }
 
 fn build_wasm_from_source() {
-  let source_path = env::var(SOURCE_DIR_KEY)
-    .map(PathBuf::from)
-    .unwrap_or_else(|_| default_source_path());
-
-  println!("cargo:warning=Building FastNoise2 for WASM with SIMD128 support");
-
   // Log the EMSDK path if set (for debugging)
   if let Ok(emsdk) = env::var("EMSDK") {
     println!("cargo:warning=EMSDK path: {}", emsdk);
   }
-  println!(
-    "cargo:rerun-if-changed={}",
-    source_path.join("include").join("FastNoise").display()
-  );
 
   // Get Emscripten SDK path from environment
   let emsdk_path = env::var("EMSDK")
@@ -172,23 +192,124 @@ fn build_wasm_from_source() {
   // Use Emscripten's CMake toolchain file - this properly configures compilers and sysroot
   let toolchain_file = format!("{}/upstream/emscripten/cmake/Modules/Platform/Emscripten.cmake", emsdk_path);
 
-  // Build FastNoise2 for WASM as a pure static library using Emscripten toolchain
-  // FastSIMD has native WASM SIMD128 support - we just need to enable it
+  build_wasm_via_cmake(
+    "Building FastNoise2 for WASM with SIMD128 support",
+    |config| {
+      // Build FastNoise2 for WASM as a pure static library using Emscripten toolchain
+      // FastSIMD has native WASM SIMD128 support - we just need to enable it
+      config.define("CMAKE_TOOLCHAIN_FILE", &toolchain_file);
+
+      // Enable WASM SIMD128 only (no threading/atomics for compatibility with simple WASM demos)
+      // NOTE: If Rust is built with --shared-memory, FastNoise2 also needs atomics (-pthread)
+      // For now, keep it simple and let individual projects add atomics if needed
+      let wasm_flags = "-msimd128";
+      config.define("CMAKE_C_FLAGS", wasm_flags);
+      config.define("CMAKE_CXX_FLAGS", wasm_flags);
+    },
+  );
+}
+
+/// Build FastNoise2 for `wasm32-unknown-unknown` with a standalone wasi-sdk
+/// clang toolchain, so the result links straight into a wasm-bindgen `cdylib`
+/// without pulling in Emscripten's JS glue.
+fn build_wasm_unknown_unknown() {
+  let wasi_sdk = WasiSdk::from_env("wasm32-unknown-unknown");
+
+  // The LLVM wasm32 target stubs out almost all of libc, so the only runtime
+  // symbols the C++ code needs are malloc/free/memcpy, which resolve against
+  // Rust std's allocator (dlmalloc). Disabling exceptions/RTTI means no
+  // landing-pad or typeinfo symbols leak into the final archive either.
+  let wasm_flags = "--target=wasm32-unknown-unknown -fno-exceptions -fno-rtti -msimd128";
+
+  build_wasm_via_cmake(
+    "Building FastNoise2 for wasm32-unknown-unknown with wasi-sdk",
+    |config| wasi_sdk.configure(config, wasm_flags),
+  );
+}
+
+/// Build FastNoise2 for `wasm32-wasip1` as a WASI reactor module, using the
+/// same wasi-sdk toolchain and sysroot as the `wasm32-unknown-unknown` path
+/// but with the WASI target triple and a reactor entry-point model instead
+/// of a host-less one.
+fn build_wasm_wasi() {
+  let wasi_sdk = WasiSdk::from_env("wasm32-wasip1");
+
+  // -mexec-model=reactor drops the implicit _start/main entry point, so the
+  // module can be loaded once and have its exports called repeatedly by the
+  // host instead of running a single pass and exiting like a WASI command.
+  let wasm_flags =
+    "--target=wasm32-wasi -mexec-model=reactor -fno-exceptions -fno-rtti -msimd128";
+
+  build_wasm_via_cmake(
+    "Building FastNoise2 for wasm32-wasip1 with wasi-sdk",
+    |config| wasi_sdk.configure(config, wasm_flags),
+  );
+}
+
+/// wasi-sdk toolchain paths shared by the `wasm32-unknown-unknown` and
+/// `wasm32-wasip1` build paths, which differ only in target triple / exec
+/// model flags, not in how the toolchain itself is located and wired up.
+struct WasiSdk {
+  clang: String,
+  clangxx: String,
+  sysroot: String,
+}
+
+impl WasiSdk {
+  fn from_env(builds_for: &str) -> Self {
+    let wasi_sdk_path = env::var("WASI_SDK_PATH").unwrap_or_else(|_| {
+      panic!(
+        "WASI_SDK_PATH environment variable required for {builds_for} builds; install wasi-sdk \
+         from https://github.com/WebAssembly/wasi-sdk"
+      )
+    });
+
+    Self {
+      clang: format!("{wasi_sdk_path}/bin/clang"),
+      clangxx: format!("{wasi_sdk_path}/bin/clang++"),
+      sysroot: format!("{wasi_sdk_path}/share/wasi-sysroot"),
+    }
+  }
+
+  fn configure(&self, config: &mut cmake::Config, wasm_flags: &str) {
+    config
+      .define("CMAKE_SYSTEM_NAME", "Generic")
+      .define("CMAKE_SYSTEM_PROCESSOR", "wasm32")
+      .define("CMAKE_C_COMPILER", &self.clang)
+      .define("CMAKE_CXX_COMPILER", &self.clangxx)
+      .define("CMAKE_SYSROOT", &self.sysroot)
+      .define("CMAKE_C_COMPILER_WORKS", "1")
+      .define("CMAKE_CXX_COMPILER_WORKS", "1")
+      .define("CMAKE_C_FLAGS", wasm_flags)
+      .define("CMAKE_CXX_FLAGS", wasm_flags);
+  }
+}
+
+/// Shared driver for the three WASM cmake build paths (`build_wasm_from_source`,
+/// `build_wasm_unknown_unknown`, `build_wasm_wasi`): resolves the FastNoise2
+/// source, applies the common CMake defines, lets `configure_toolchain` layer
+/// on the target-specific compiler/flags, then links, copies headers, runs
+/// the optional wasm-opt pass and generates bindings.
+fn build_wasm_via_cmake(build_label: &str, configure_toolchain: impl FnOnce(&mut cmake::Config)) {
+  let source_path = env::var(SOURCE_DIR_KEY)
+    .map(PathBuf::from)
+    .unwrap_or_else(|_| default_source_path());
+
+  println!("cargo:warning={build_label}");
+  println!(
+    "cargo:rerun-if-changed={}",
+    source_path.join("include").join("FastNoise").display()
+  );
+
   let mut config = cmake::Config::new(&source_path);
   config
     .profile("Release")
-    .define("CMAKE_TOOLCHAIN_FILE", &toolchain_file)
     .define("FASTNOISE2_TOOLS", "OFF")
     .define("FASTNOISE2_TESTS", "OFF")
-    .define("FASTNOISE2_UTILITY", "OFF")  // Disable utility to avoid Corrade dependency
+    .define("FASTNOISE2_UTILITY", "OFF")
     .define("BUILD_SHARED_LIBS", "OFF");
 
-  // Enable WASM SIMD128 only (no threading/atomics for compatibility with simple WASM demos)
-  // NOTE: If Rust is built with --shared-memory, FastNoise2 also needs atomics (-pthread)
-  // For now, keep it simple and let individual projects add atomics if needed
-  let wasm_flags = "-msimd128";
-  config.define("CMAKE_C_FLAGS", wasm_flags);
-  config.define("CMAKE_CXX_FLAGS", wasm_flags);
+  configure_toolchain(&mut config);
 
   let out_path = config.build();
   let lib_path = out_path.join("lib");
@@ -198,7 +319,15 @@ fn build_wasm_from_source() {
   println!("cargo:rustc-link-search=native={}", lib64_path.display());
   println!("cargo:rustc-link-lib=static={LIB_NAME}");
 
-  // Copy Utility headers that cmake doesn't install
+  copy_utility_headers(&source_path, &out_path);
+
+  maybe_optimize_wasm(&out_path);
+  generate_bindings(out_path);
+}
+
+/// Copy the `FastNoise/Utility` headers that cmake's install step doesn't
+/// carry over, so `generate_bindings`'s bindgen pass can still see them.
+fn copy_utility_headers(source_path: &std::path::Path, out_path: &std::path::Path) {
   let src_utility = source_path
     .join("include")
     .join("FastNoise")
@@ -212,11 +341,108 @@ fn build_wasm_from_source() {
       std::fs::copy(entry.path(), &dst).expect("Failed to copy header");
     }
   }
+}
 
-  generate_bindings(out_path);
+/// Link against a FastNoise2 already installed on the system instead of
+/// building it: try pkg-config first, then scan standard install prefixes.
+fn link_system_fastnoise2() {
+  if let Some((lib_dir, include_root)) = try_pkg_config() {
+    println!(
+      "cargo:warning=found system FastNoise2 via pkg-config ('{}')",
+      lib_dir.display()
+    );
+    println!("cargo:rustc-link-search=native={}", lib_dir.display());
+    println!("cargo:rustc-link-lib=static={LIB_NAME}");
+    generate_bindings(include_root);
+    return;
+  }
+
+  let mut searched = Vec::new();
+
+  if let Ok(override_path) = env::var(SYSTEM_LIBRARY_PATH_KEY) {
+    searched.push(PathBuf::from(override_path));
+  }
+  searched.extend(STANDARD_SYSTEM_PREFIXES.iter().map(PathBuf::from));
+
+  for prefix in &searched {
+    let lib_dir = prefix.join("lib");
+    let header_path = prefix
+      .join("include")
+      .join("FastNoise")
+      .join(HEADER_NAME);
+    let static_lib = lib_dir.join(format!("lib{LIB_NAME}.a"));
+    let import_lib = lib_dir.join(format!("{LIB_NAME}.lib"));
+
+    if header_path.exists() && (static_lib.exists() || import_lib.exists()) {
+      println!(
+        "cargo:warning=found system FastNoise2 under '{}'",
+        prefix.display()
+      );
+      println!("cargo:rustc-link-search=native={}", lib_dir.display());
+      println!("cargo:rustc-link-lib=static={LIB_NAME}");
+      generate_bindings(prefix.clone());
+      return;
+    }
+  }
+
+  panic!(
+    "system feature enabled but no installed FastNoise2 was found.\n\
+     Searched via pkg-config (package '{PKG_CONFIG_NAME}') and under: {}.\n\
+     Install FastNoise2 system-wide, or set {SYSTEM_LIBRARY_PATH_KEY} to its install prefix \
+     (containing lib/lib{LIB_NAME}.a and include/FastNoise/{HEADER_NAME}).",
+    searched
+      .iter()
+      .map(|p| p.display().to_string())
+      .collect::<Vec<_>>()
+      .join(", ")
+  );
+}
+
+/// Probe pkg-config for an installed FastNoise2, returning the link search
+/// directory and a synthetic "source root" (`generate_bindings` expects one
+/// whose `include/FastNoise/` subdirectory holds the header) on success.
+fn try_pkg_config() -> Option<(PathBuf, PathBuf)> {
+  let output = Command::new("pkg-config")
+    .args(["--silence-errors", "--cflags", "--libs", PKG_CONFIG_NAME])
+    .output()
+    .ok()?;
+  if !output.status.success() {
+    return None;
+  }
+
+  let flags = String::from_utf8_lossy(&output.stdout);
+  let mut lib_dir = None;
+  let mut include_root = None;
+
+  for token in flags.split_whitespace() {
+    if let Some(path) = token.strip_prefix("-L") {
+      lib_dir = Some(PathBuf::from(path));
+    } else if let Some(path) = token.strip_prefix("-I") {
+      // pkg-config's -I points at "<prefix>/include/FastNoise" directly; walk
+      // back up to "<prefix>" to match generate_bindings' expected layout.
+      let fastnoise_dir = PathBuf::from(path);
+      if let Some(prefix) = fastnoise_dir.parent().and_then(|p| p.parent()) {
+        include_root = Some(prefix.to_path_buf());
+      }
+    }
+  }
+
+  Some((lib_dir?, include_root?))
 }
 
 fn build_from_source() {
+  if env::var("CARGO_FEATURE_CC_BUILD").is_ok() {
+    println!(
+      "cargo:warning=feature 'cc-build' is enabled; compiling FastNoise2 directly with the `cc` \
+       crate instead of cmake"
+    );
+    build_from_source_cc();
+  } else {
+    build_from_source_cmake();
+  }
+}
+
+fn build_from_source_cmake() {
   let source_path = env::var(SOURCE_DIR_KEY)
     .map(PathBuf::from)
     .unwrap_or_else(|_| default_source_path());
@@ -275,22 +501,137 @@ fn build_from_source() {
   println!("cargo:rustc-link-search=native={}", lib64_path.display());
   println!("cargo:rustc-link-lib=static={LIB_NAME}");
 
-  // Copy Utility headers that cmake doesn't install
-  let src_utility = source_path
-    .join("include")
-    .join("FastNoise")
-    .join("Utility");
-  let dst_utility = out_path.join("include").join("FastNoise").join("Utility");
-  if src_utility.exists() && !dst_utility.exists() {
-    std::fs::create_dir_all(&dst_utility).expect("Failed to create Utility dir");
-    for entry in std::fs::read_dir(&src_utility).expect("Failed to read Utility dir") {
-      let entry = entry.expect("Failed to read entry");
-      let dst = dst_utility.join(entry.file_name());
-      std::fs::copy(entry.path(), &dst).expect("Failed to copy header");
+  copy_utility_headers(&source_path, &out_path);
+
+  generate_bindings(out_path);
+}
+
+/// Alternative to `build_from_source_cmake` that compiles the FastNoise2
+/// C++/C-API translation units directly with the `cc` crate instead of
+/// shelling out to cmake. Gives better incremental caching and honors
+/// `CC`/`CXX`/`TARGET` for cross-compilation without cmake-rs's release-flag
+/// fights or the install/headcopy dance, at the cost of reimplementing the
+/// handful of CMake options FastNoise2 needs.
+fn build_from_source_cc() {
+  let source_path = env::var(SOURCE_DIR_KEY)
+    .map(PathBuf::from)
+    .unwrap_or_else(|_| default_source_path());
+
+  println!(
+    "cargo:warning=building from source files located in '{}' via the `cc` crate",
+    source_path.display()
+  );
+  let src_dir = source_path.join("src");
+  println!("cargo:rerun-if-changed={}", src_dir.display());
+  println!(
+    "cargo:rerun-if-changed={}",
+    source_path.join("include").join("FastNoise").display()
+  );
+
+  let target_env = env::var("CARGO_CFG_TARGET_ENV").unwrap();
+  let target_arch = env::var("CARGO_CFG_TARGET_ARCH").unwrap();
+
+  // FastSIMD compiles the same generator code once per supported x86 ISA
+  // level (scalar baseline, SSE4.1, AVX2, AVX512) into separate translation
+  // units and dispatches between them at runtime, the same way the cmake
+  // build applies each level's ISA flags only to that level's source file
+  // via per-file COMPILE_OPTIONS. Group sources by the level their file name
+  // encodes instead of blanket-flagging every file with AVX2/FMA, which
+  // would make the baseline/dispatcher units assume AVX2 is always available
+  // and SIGILL on pre-Haswell/non-AVX2 CPUs.
+  let mut groups: Vec<(&'static str, Vec<&'static str>, Vec<PathBuf>)> = Vec::new();
+  for source in collect_sources(&src_dir, &["cpp"]) {
+    let (level, isa_flags) = if target_env != "msvc" && target_arch == "x86_64" {
+      x86_isa_level_for(&source)
+    } else {
+      ("baseline", Vec::new())
+    };
+
+    match groups.iter_mut().find(|(existing, _, _)| *existing == level) {
+      Some((_, _, sources)) => sources.push(source),
+      None => groups.push((level, isa_flags, vec![source])),
     }
   }
 
-  generate_bindings(out_path);
+  for (level, isa_flags, sources) in &groups {
+    let mut build = cc::Build::new();
+    build
+      .cpp(true)
+      .include(source_path.join("include"))
+      .warnings(false);
+
+    if target_env == "msvc" {
+      build.flag("/O2").flag("/Ob2").define("NDEBUG", None);
+    } else {
+      build.flag("-O3").define("NDEBUG", None);
+      for flag in isa_flags {
+        build.flag(flag);
+      }
+    }
+
+    for source in sources {
+      build.file(source);
+    }
+
+    // `cc::Build::compile` both compiles and archives the library into
+    // OUT_DIR and emits the rustc-link-search/lib directives itself. Each
+    // ISA level gets its own static lib since `cc::Build` only applies one
+    // set of flags per invocation.
+    build.compile(&format!("{LIB_NAME}_{level}"));
+
+    println!(
+      "cargo:warning=cc-build: compiled {} file(s) for ISA level '{level}' with flags {isa_flags:?}",
+      sources.len()
+    );
+  }
+
+  generate_bindings(source_path);
+}
+
+/// Map a FastNoise2 source file to the x86 ISA level its name encodes (e.g.
+/// `*_AVX2.cpp`) and the flags that level needs, or `"baseline"` with no
+/// extra flags for scalar/SSE2 and runtime-dispatch translation units.
+fn x86_isa_level_for(source: &std::path::Path) -> (&'static str, Vec<&'static str>) {
+  const X86_ISA_LEVELS: &[(&str, &[&str])] = &[
+    ("avx512", &["-mavx512f", "-mavx512dq", "-mavx512vl", "-mavx512bw", "-mfma"]),
+    ("avx2", &["-mavx2", "-mfma"]),
+    ("sse41", &["-msse4.1"]),
+    ("sse4_1", &["-msse4.1"]),
+  ];
+
+  let name = source
+    .file_name()
+    .and_then(|n| n.to_str())
+    .unwrap_or_default()
+    .to_ascii_lowercase();
+
+  for (level, flags) in X86_ISA_LEVELS {
+    if name.contains(level) {
+      return (level, flags.to_vec());
+    }
+  }
+  ("baseline", Vec::new())
+}
+
+/// Recursively collect files under `dir` whose extension matches one of `exts`.
+fn collect_sources(dir: &std::path::Path, exts: &[&str]) -> Vec<PathBuf> {
+  let mut sources = Vec::new();
+  let Ok(entries) = std::fs::read_dir(dir) else {
+    return sources;
+  };
+  for entry in entries.flatten() {
+    let path = entry.path();
+    if path.is_dir() {
+      sources.extend(collect_sources(&path, exts));
+    } else if path
+      .extension()
+      .and_then(|ext| ext.to_str())
+      .is_some_and(|ext| exts.contains(&ext))
+    {
+      sources.push(path);
+    }
+  }
+  sources
 }
 
 fn generate_bindings(source_path: PathBuf) {
@@ -299,11 +640,26 @@ fn generate_bindings(source_path: PathBuf) {
 
   // For WASM builds, use vendored bindings (bindgen has issues with WASM target)
   // The C API bindings are platform-agnostic anyway (pure extern "C" declarations)
+  //
+  // NOTE: this only wires up the lookup path for `bindings_vendored_wasi.rs`,
+  // mirroring the existing `bindings_vendored.rs` path -- the file itself
+  // isn't checked in by this change, since generating it needs a bindgen run
+  // against the actual wasi-sdk sysroot headers, which this tree doesn't
+  // vendor. Until a maintainer adds it, builds fall through to live bindgen
+  // generation below, same as they do today for `bindings_vendored.rs`.
   let target_arch = env::var("CARGO_CFG_TARGET_ARCH").unwrap_or_default();
   if target_arch == "wasm32" {
+    // WASI gets its own vendored file since it's generated against the WASI
+    // sysroot headers rather than Emscripten's/wasi-sdk's unknown-unknown ones.
+    let target_os = env::var("CARGO_CFG_TARGET_OS").unwrap_or_default();
+    let vendored_name = if target_os == "wasi" {
+      "bindings_vendored_wasi.rs"
+    } else {
+      "bindings_vendored.rs"
+    };
     let vendored_bindings = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap())
       .join("src")
-      .join("bindings_vendored.rs");
+      .join(vendored_name);
 
     if vendored_bindings.exists() {
       println!(
@@ -318,6 +674,36 @@ fn generate_bindings(source_path: PathBuf) {
     }
   }
 
+  // Prefer a checked-in bindings file for this target over paying the
+  // bindgen/libclang cost on every clean build -- unless `update-bindings` is
+  // enabled, in which case we want to fall through and regenerate it instead
+  // of just re-copying the stale copy back to itself.
+  //
+  // NOTE: this lands the lookup/regeneration mechanism only. No files under
+  // `src/bindings/` are checked in by this change -- doing so needs a real
+  // bindgen run against a full FastNoise2 checkout for each target triple,
+  // which isn't available in this tree. A maintainer populates
+  // `src/bindings/<triple>.rs` per triple by building once with
+  // `--features update-bindings` against that target.
+  let triple_key = target_triple_key();
+  let checked_in_bindings = checked_in_bindings_path(&triple_key);
+  let update_bindings = env::var("CARGO_FEATURE_UPDATE_BINDINGS").is_ok();
+  if checked_in_bindings.exists() && !update_bindings {
+    println!(
+      "cargo:warning=using checked-in bindings for '{triple_key}' from '{}'",
+      checked_in_bindings.display()
+    );
+    std::fs::copy(&checked_in_bindings, &bindings_path)
+      .expect("Failed to copy checked-in bindings");
+    return;
+  } else if !checked_in_bindings.exists() && !update_bindings {
+    println!(
+      "cargo:warning=no checked-in bindings for '{triple_key}'; falling back to bindgen. Build \
+       once with --features update-bindings to populate '{}' for next time",
+      checked_in_bindings.display()
+    );
+  }
+
   // Check for cached bindings
   if let Ok(cache_dir) = env::var(BINDINGS_CACHE_KEY) {
     let cached_bindings = PathBuf::from(&cache_dir).join("bindings.rs");
@@ -373,6 +759,346 @@ fn generate_bindings(source_path: PathBuf) {
       cached_bindings.display()
     );
   }
+
+  // With the `update-bindings` feature, deliberately refresh the checked-in
+  // copy for this target instead of relying on maintainers to hand-edit it.
+  if update_bindings {
+    std::fs::create_dir_all(checked_in_bindings.parent().unwrap())
+      .expect("Failed to create src/bindings directory");
+    std::fs::copy(&bindings_path, &checked_in_bindings)
+      .expect("Failed to update checked-in bindings");
+    println!(
+      "cargo:warning=update-bindings: wrote '{}'",
+      checked_in_bindings.display()
+    );
+  }
+}
+
+/// Key used to select a checked-in bindings file for the current target,
+/// e.g. `x86_64-linux-gnu` or `aarch64-macos-`.
+fn target_triple_key() -> String {
+  let arch = env::var("CARGO_CFG_TARGET_ARCH").unwrap_or_default();
+  let os = env::var("CARGO_CFG_TARGET_OS").unwrap_or_default();
+  let env_abi = env::var("CARGO_CFG_TARGET_ENV").unwrap_or_default();
+  format!("{arch}-{os}-{env_abi}")
+}
+
+fn checked_in_bindings_path(triple_key: &str) -> PathBuf {
+  PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap())
+    .join("src")
+    .join("bindings")
+    .join(format!("{triple_key}.rs"))
+}
+
+/// Optionally run the `wasm-opt` binaryen tool over the build output. A no-op
+/// unless the `wasm-opt` cargo feature is enabled, since the pass requires
+/// binaryen to be available and adds real build time.
+///
+/// The sys crate only ever emits a static archive here (the final `.wasm` is
+/// linked by the downstream crate), so the common case optimizes the
+/// archive's member objects in place. Relocatable objects don't carry a
+/// memory section -- the initial-memory size is decided by the linker once
+/// it produces the final module -- so the configurable page-ceiling check
+/// (`check_memory_page_ceiling`) only runs in the linked-`.wasm` branch,
+/// which no current build path reaches; see that function's doc comment.
+fn maybe_optimize_wasm(out_path: &std::path::Path) {
+  if env::var("CARGO_FEATURE_WASM_OPT").is_err() {
+    return;
+  }
+
+  let Some(wasm_opt) = find_wasm_opt() else {
+    panic!(
+      "wasm-opt feature enabled but no wasm-opt binary found on PATH or via {WASM_OPT_PATH_KEY}"
+    );
+  };
+
+  let level = env::var(WASM_OPT_LEVEL_KEY).unwrap_or_else(|_| "O2".to_string());
+  let level_flag = format!("-{level}");
+
+  let modules = find_files_with_ext(out_path, "wasm");
+  if !modules.is_empty() {
+    for module in &modules {
+      run_wasm_opt(&wasm_opt, &level_flag, module, module);
+      check_memory_page_ceiling(module);
+    }
+    return;
+  }
+
+  let archives = find_files_with_ext(out_path, "a");
+  if archives.is_empty() {
+    panic!(
+      "wasm-opt feature enabled but neither a .wasm module nor a static archive was found under \
+       '{}'; nothing was optimized",
+      out_path.display()
+    );
+  }
+
+  println!(
+    "cargo:warning=wasm-opt: optimizing static archive member objects; the \
+     {WASM_MAX_MEMORY_PAGES_KEY} ceiling is NOT enforced here -- relocatable objects don't carry \
+     a final memory size, only a linked .wasm module does. Run the check again against the \
+     module your downstream crate links before relying on it to catch memory regressions."
+  );
+  for archive in &archives {
+    optimize_archive_objects(&wasm_opt, &level_flag, archive);
+  }
+}
+
+/// Extract an archive's `.o` members one at a time with `ar p`, run `wasm-opt`
+/// on each in place, then re-archive them back with `ar rcs` so the optimized
+/// code ends up in the static library that actually gets linked.
+///
+/// Members are extracted by their position in the archive listing (`ar t`)
+/// rather than with a single `ar x` into a shared directory: `ar x` names
+/// each extracted file after the member's own basename, so two translation
+/// units with the same filename in different source subdirectories (e.g.
+/// FastNoise2 having a same-named file under `Generators/` and `Utility/`)
+/// would silently clobber each other, and the re-archived `.a` would end up
+/// quietly missing a translation unit. Prefixing each extracted file with its
+/// archive index makes every extraction unique regardless of member names.
+fn optimize_archive_objects(wasm_opt: &std::path::Path, level_flag: &str, archive: &std::path::Path) {
+  let archive = archive
+    .canonicalize()
+    .unwrap_or_else(|e| panic!("failed to resolve archive path '{}': {e}", archive.display()));
+  let work_dir = archive.with_extension("wasm-opt-objs");
+  std::fs::create_dir_all(&work_dir).expect("Failed to create wasm-opt work directory");
+
+  let members = list_archive_members(&archive);
+  let object_members: Vec<&String> = members.iter().filter(|m| m.ends_with(".o")).collect();
+  if object_members.is_empty() {
+    panic!(
+      "wasm-opt feature enabled but archive '{}' contained no .o members to optimize",
+      archive.display()
+    );
+  }
+
+  let mut objects = Vec::new();
+  let mut seen: std::collections::HashMap<&str, usize> = std::collections::HashMap::new();
+  for (index, member) in object_members.iter().enumerate() {
+    let occurrence = seen.entry(member.as_str()).or_insert(0);
+    *occurrence += 1;
+    let basename = std::path::Path::new(member)
+      .file_name()
+      .and_then(|n| n.to_str())
+      .unwrap_or(member);
+    let dest = work_dir.join(format!("{index:04}-{basename}"));
+    extract_archive_member(&archive, member, *occurrence, &dest);
+    objects.push(dest);
+  }
+
+  for object in &objects {
+    run_wasm_opt(wasm_opt, level_flag, object, object);
+  }
+
+  // The re-archived members carry index-prefixed names, so `ar rcs` would
+  // append them next to the originals; start from an empty archive instead.
+  std::fs::remove_file(&archive).expect("Failed to remove archive before re-archiving");
+  run_command(
+    Command::new("ar")
+      .arg("rcs")
+      .arg(&archive)
+      .args(&objects)
+      .current_dir(&work_dir),
+  );
+}
+
+/// List an archive's member names in order via `ar t`.
+fn list_archive_members(archive: &std::path::Path) -> Vec<String> {
+  let output = Command::new("ar")
+    .arg("t")
+    .arg(archive)
+    .output()
+    .unwrap_or_else(|e| panic!("failed to run `ar t {}`: {e}", archive.display()));
+  if !output.status.success() {
+    panic!("`ar t {}` failed with {}", archive.display(), output.status);
+  }
+  String::from_utf8_lossy(&output.stdout)
+    .lines()
+    .map(str::trim)
+    .filter(|line| !line.is_empty())
+    .map(str::to_string)
+    .collect()
+}
+
+/// Extract the `occurrence`-th (1-based) member named `member` from `archive`
+/// to `dest` via `ar pN`, which prints that one member's contents to stdout
+/// instead of writing it to a file named after the member (what `ar x` does),
+/// so the caller controls the destination filename and can keep same-named
+/// members from colliding.
+fn extract_archive_member(
+  archive: &std::path::Path,
+  member: &str,
+  occurrence: usize,
+  dest: &std::path::Path,
+) {
+  let output = Command::new("ar")
+    .arg("pN")
+    .arg(occurrence.to_string())
+    .arg(archive)
+    .arg(member)
+    .output()
+    .unwrap_or_else(|e| panic!("failed to run `ar p {} {member}`: {e}", archive.display()));
+  if !output.status.success() {
+    panic!("`ar p {} {member}` failed with {}", archive.display(), output.status);
+  }
+  std::fs::write(dest, &output.stdout)
+    .unwrap_or_else(|e| panic!("failed to write extracted member to '{}': {e}", dest.display()));
+}
+
+fn run_wasm_opt(
+  wasm_opt: &std::path::Path,
+  level_flag: &str,
+  input: &std::path::Path,
+  output: &std::path::Path,
+) {
+  println!(
+    "cargo:warning=running wasm-opt {level_flag} on '{}'",
+    input.display()
+  );
+  run_command(
+    Command::new(wasm_opt)
+      .arg(level_flag)
+      .arg(input)
+      .arg("-o")
+      .arg(output),
+  );
+}
+
+/// Run `command`, panicking with its program name and status on any failure
+/// to spawn or non-zero exit, since a silently-skipped step here would mean
+/// "optimized" output that was never actually optimized.
+fn run_command(command: &mut Command) {
+  let program = command.get_program().to_string_lossy().into_owned();
+  let status = command
+    .status()
+    .unwrap_or_else(|e| panic!("failed to run `{program}`: {e}"));
+  if !status.success() {
+    panic!("`{program}` failed with {status}");
+  }
+}
+
+/// Locate the `wasm-opt` binary: an explicit `WASM_OPT_PATH` override takes
+/// priority, then a vendored copy under the crate's `tools/` directory, then
+/// whatever `wasm-opt` resolves to on `PATH`.
+fn find_wasm_opt() -> Option<PathBuf> {
+  if let Ok(path) = env::var(WASM_OPT_PATH_KEY) {
+    let path = PathBuf::from(path);
+    if path.exists() {
+      return Some(path);
+    }
+  }
+
+  let vendored = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap())
+    .join("tools")
+    .join("wasm-opt");
+  if vendored.exists() {
+    return Some(vendored);
+  }
+
+  Command::new("wasm-opt")
+    .arg("--version")
+    .output()
+    .ok()
+    .filter(|o| o.status.success())
+    .map(|_| PathBuf::from("wasm-opt"))
+}
+
+/// Recursively collect files under `dir` whose extension is exactly `ext`.
+fn find_files_with_ext(dir: &std::path::Path, ext: &str) -> Vec<PathBuf> {
+  let mut out = Vec::new();
+  let Ok(entries) = std::fs::read_dir(dir) else {
+    return out;
+  };
+  for entry in entries.flatten() {
+    let path = entry.path();
+    if path.is_dir() {
+      out.extend(find_files_with_ext(&path, ext));
+    } else if path.extension().is_some_and(|e| e == ext) {
+      out.push(path);
+    }
+  }
+  out
+}
+
+/// Parse a linked WASM module's memory section and fail the build if the
+/// initial size exceeds the configurable page ceiling (`WASM_MAX_MEMORY_PAGES`,
+/// default `DEFAULT_WASM_MAX_MEMORY_PAGES` pages, 64 KiB each). Only callable
+/// against an actual `.wasm` module -- no current build path produces one
+/// from the sys crate, see `maybe_optimize_wasm`.
+fn check_memory_page_ceiling(module: &std::path::Path) {
+  let max_pages: u64 = env::var(WASM_MAX_MEMORY_PAGES_KEY)
+    .ok()
+    .and_then(|v| v.parse().ok())
+    .unwrap_or(DEFAULT_WASM_MAX_MEMORY_PAGES);
+
+  let bytes = std::fs::read(module)
+    .unwrap_or_else(|e| panic!("failed to read '{}' for size check: {e}", module.display()));
+
+  let Some(initial_pages) = parse_memory_section_initial_pages(&bytes) else {
+    println!(
+      "cargo:warning=could not find a memory section in '{}'; skipping page ceiling check",
+      module.display()
+    );
+    return;
+  };
+
+  if initial_pages > max_pages {
+    panic!(
+      "WASM module '{}' requests {initial_pages} initial memory page(s) \
+       ({} bytes), exceeding the configured ceiling of {max_pages} page(s) \
+       ({} bytes). Set {WASM_MAX_MEMORY_PAGES_KEY} to raise the limit.",
+      module.display(),
+      initial_pages * WASM_PAGE_SIZE_BYTES,
+      max_pages * WASM_PAGE_SIZE_BYTES,
+    );
+  }
+}
+
+/// Minimal WASM binary format walker: skips the 8-byte header, then scans
+/// sections looking for the memory section (id 5), returning the first
+/// memtype's initial page count.
+fn parse_memory_section_initial_pages(bytes: &[u8]) -> Option<u64> {
+  const MEMORY_SECTION_ID: u8 = 5;
+
+  fn read_u32_leb(bytes: &[u8], pos: &mut usize) -> Option<u64> {
+    let mut result: u64 = 0;
+    let mut shift = 0;
+    loop {
+      let byte = *bytes.get(*pos)?;
+      *pos += 1;
+      result |= ((byte & 0x7f) as u64) << shift;
+      if byte & 0x80 == 0 {
+        break;
+      }
+      shift += 7;
+    }
+    Some(result)
+  }
+
+  if bytes.len() < 8 || &bytes[0..4] != b"\0asm" {
+    return None;
+  }
+
+  let mut pos = 8;
+  while pos < bytes.len() {
+    let section_id = bytes[pos];
+    pos += 1;
+    let section_len = read_u32_leb(bytes, &mut pos)? as usize;
+    let section_end = pos + section_len;
+
+    if section_id == MEMORY_SECTION_ID {
+      let _memory_count = read_u32_leb(bytes, &mut pos)?;
+      let flags = *bytes.get(pos)?;
+      pos += 1;
+      let initial_pages = read_u32_leb(bytes, &mut pos)?;
+      let _ = flags; // max-pages (when present) isn't needed for this check
+      return Some(initial_pages);
+    }
+
+    pos = section_end;
+  }
+
+  None
 }
 
 fn default_source_path() -> PathBuf {